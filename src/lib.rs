@@ -7,27 +7,209 @@ use std::marker::PhantomData;
 use std::time::{Duration, Instant};
 
 use diesel::backend::Backend;
+use diesel::connection::instrumentation::{Instrumentation, InstrumentationEvent, StrQueryHelper};
 use diesel::debug_query;
 use diesel::prelude::*;
 use diesel::query_builder::{AsQuery, QueryFragment, QueryId};
+use diesel_async::pooled_connection::{
+    AsyncDieselConnectionManager, PoolableConnection, RecyclingMethod,
+};
 use diesel_async::{
     AsyncConnection, SimpleAsyncConnection, TransactionManager, TransactionManagerStatus,
 };
 use futures_util::future::BoxFuture;
 use futures_util::FutureExt;
+use log::Level;
+
+/// Controls how a [`LoggingConnection`] times and logs each query.
+///
+/// The defaults reproduce the original hardcoded behaviour: a `debug`
+/// record on every query, an `info` once a query crosses 1 second, and a
+/// `warn` once it crosses 5 seconds. Build a custom config with
+/// [`LoggingConfig::builder`] to move the thresholds, pick a different
+/// [`Level`] for each band, route the records to a dedicated `log`
+/// target, or silence the per-query `debug` flood below a minimum
+/// duration on high-throughput services.
+#[derive(Debug, Clone)]
+pub struct LoggingConfig {
+    /// Queries at or above this duration are logged at `info_level`.
+    pub info_threshold: Duration,
+    /// Queries at or above this duration are logged at `warn_level`.
+    pub warn_threshold: Duration,
+    /// Level used for queries below `info_threshold`.
+    pub default_level: Level,
+    /// Level used between `info_threshold` and `warn_threshold`.
+    pub info_level: Level,
+    /// Level used at or above `warn_threshold`.
+    pub warn_level: Level,
+    /// Optional `log` target for the emitted records.
+    pub target: Option<String>,
+    /// Queries faster than this are not logged at all. Leave `None` to
+    /// log every query.
+    pub min_duration: Option<Duration>,
+    /// Emit a structured JSON record (`sql`, `duration_ms`, `count`,
+    /// `params`) instead of the inlined text query. Keeps literal bind
+    /// values out of the SQL string and lets log pipelines ingest queries
+    /// as data.
+    pub structured: bool,
+    /// How bind parameters are scrubbed before they reach the sink when
+    /// [`structured`](Self::structured) output is enabled.
+    pub redaction: Redaction,
+}
+
+/// Redaction policy applied to captured bind parameters in structured
+/// output. Redacted values are replaced with `"<redacted>"`.
+#[derive(Debug, Clone, Default)]
+pub enum Redaction {
+    /// Emit every bind value verbatim.
+    #[default]
+    None,
+    /// Redact every bind value.
+    All,
+    /// Redact only the binds at these zero-based positions, for columns
+    /// known to carry sensitive values.
+    Positions(Vec<usize>),
+}
+
+impl Redaction {
+    /// Placeholder substituted for a redacted bind value.
+    const PLACEHOLDER: &'static str = "<redacted>";
+
+    fn redacts(&self, position: usize) -> bool {
+        match self {
+            Redaction::None => false,
+            Redaction::All => true,
+            Redaction::Positions(positions) => positions.contains(&position),
+        }
+    }
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        Self {
+            info_threshold: Duration::from_secs(1),
+            warn_threshold: Duration::from_secs(5),
+            default_level: Level::Debug,
+            info_level: Level::Info,
+            warn_level: Level::Warn,
+            target: None,
+            min_duration: None,
+            structured: false,
+            redaction: Redaction::None,
+        }
+    }
+}
+
+impl LoggingConfig {
+    /// Start building a [`LoggingConfig`] from the defaults.
+    pub fn builder() -> LoggingConfigBuilder {
+        LoggingConfigBuilder {
+            config: Self::default(),
+        }
+    }
+
+    /// The level a query of the given `duration` should be logged at, or
+    /// `None` when it falls below `min_duration` and should be skipped.
+    fn level_for(&self, duration: Duration) -> Option<Level> {
+        if let Some(min) = self.min_duration {
+            if duration < min {
+                return None;
+            }
+        }
+        if duration >= self.warn_threshold {
+            Some(self.warn_level)
+        } else if duration >= self.info_threshold {
+            Some(self.info_level)
+        } else {
+            Some(self.default_level)
+        }
+    }
+}
+
+/// Fluent builder for [`LoggingConfig`], mirroring the `LevelFilter`-style
+/// options sqlx exposes on its connection configuration.
+#[derive(Debug, Clone)]
+pub struct LoggingConfigBuilder {
+    config: LoggingConfig,
+}
+
+impl LoggingConfigBuilder {
+    /// Set the duration at which queries start logging at `info_level`.
+    pub fn info_threshold(mut self, threshold: Duration) -> Self {
+        self.config.info_threshold = threshold;
+        self
+    }
+
+    /// Set the duration at which queries start logging at `warn_level`.
+    pub fn warn_threshold(mut self, threshold: Duration) -> Self {
+        self.config.warn_threshold = threshold;
+        self
+    }
+
+    /// Set the level used for queries below `info_threshold`.
+    pub fn default_level(mut self, level: Level) -> Self {
+        self.config.default_level = level;
+        self
+    }
+
+    /// Set the level used between `info_threshold` and `warn_threshold`.
+    pub fn info_level(mut self, level: Level) -> Self {
+        self.config.info_level = level;
+        self
+    }
+
+    /// Set the level used at or above `warn_threshold`.
+    pub fn warn_level(mut self, level: Level) -> Self {
+        self.config.warn_level = level;
+        self
+    }
+
+    /// Route the emitted records to a dedicated `log` target.
+    pub fn target(mut self, target: impl Into<String>) -> Self {
+        self.config.target = Some(target.into());
+        self
+    }
+
+    /// Suppress logging for any query faster than `min`.
+    pub fn min_duration(mut self, min: Duration) -> Self {
+        self.config.min_duration = Some(min);
+        self
+    }
+
+    /// Emit structured JSON records instead of inlined text queries.
+    pub fn structured(mut self, structured: bool) -> Self {
+        self.config.structured = structured;
+        self
+    }
+
+    /// Set the bind-parameter redaction policy for structured output.
+    pub fn redaction(mut self, redaction: Redaction) -> Self {
+        self.config.redaction = redaction;
+        self
+    }
+
+    /// Finish building the [`LoggingConfig`].
+    pub fn build(self) -> LoggingConfig {
+        self.config
+    }
+}
 
 /// Wraps a diesel `Connection` to time and log each query using
 /// the configured logger for the `log` crate.
 ///
-/// Currently, this produces a `debug` log on every query,
+/// By default this produces a `debug` log on every query,
 /// an `info` on queries that take longer than 1 second,
 /// and a `warn`ing on queries that take longer than 5 seconds.
-/// These thresholds will be configurable in a future version.
+/// Pass a [`LoggingConfig`] to [`LoggingConnection::with_config`] to
+/// change the thresholds, the level used for each band, the `log`
+/// target, or to suppress fast queries entirely.
 pub struct LoggingConnection<C>
 where
     C: AsyncConnection,
 {
     connection: C,
+    config: LoggingConfig,
+    instrumentation: Box<dyn Instrumentation>,
     transaction_manager: LoggingTransactionManager<C>,
 }
 
@@ -38,7 +220,22 @@ where
     <C::Backend as Backend>::QueryBuilder: Default,
 {
     async fn batch_execute(&mut self, query: &str) -> QueryResult<()> {
-        self.connection.batch_execute(query).await
+        self.instrumentation
+            .on_connection_event(InstrumentationEvent::start_query(&StrQueryHelper::new(query)));
+        let start_time = Instant::now();
+        let result = self.connection.batch_execute(query).await;
+        let duration = start_time.elapsed();
+        self.instrumentation
+            .on_connection_event(InstrumentationEvent::finish_query(
+                &StrQueryHelper::new(query),
+                result.as_ref().err(),
+            ));
+        if self.config.structured {
+            // `batch_execute` carries no separate binds, so the record has an
+            // empty `params` array and no `count`.
+            log_structured(&self.config, query, duration, None);
+        }
+        result
     }
 }
 
@@ -49,7 +246,8 @@ where
     <C as AsyncConnection>::Backend: std::default::Default,
     <C::Backend as Backend>::QueryBuilder: Default,
 {
-    type LoadFuture<'conn, 'query> = <C as AsyncConnection>::LoadFuture<'conn, 'query>;
+    type LoadFuture<'conn, 'query> =
+        BoxFuture<'query, QueryResult<<C as AsyncConnection>::Stream<'conn, 'query>>>;
     type ExecuteFuture<'conn, 'query> = BoxFuture<'query, QueryResult<usize>>;
     type Stream<'conn, 'query> = <C as AsyncConnection>::Stream<'conn, 'query>;
     type Row<'conn, 'query> = <C as AsyncConnection>::Row<'conn, 'query>;
@@ -57,7 +255,17 @@ where
     type TransactionManager = LoggingTransactionManager<C>;
 
     async fn establish(database_url: &str) -> ConnectionResult<Self> {
-        Ok(LoggingConnection::new(C::establish(database_url).await?))
+        let mut instrumentation = Box::new(LogInstrumentation::new(LoggingConfig::default()));
+        instrumentation
+            .on_connection_event(InstrumentationEvent::start_establish_connection(database_url));
+        let result = C::establish(database_url).await;
+        instrumentation.on_connection_event(InstrumentationEvent::finish_establish_connection(
+            database_url,
+            result.as_ref().err(),
+        ));
+        let mut conn = LoggingConnection::new(result?);
+        conn.instrumentation = instrumentation;
+        Ok(conn)
     }
 
     fn load<'conn, 'query, T>(&'conn mut self, source: T) -> Self::LoadFuture<'conn, 'query>
@@ -69,10 +277,29 @@ where
         let debug_query = debug_query::<Self::Backend, _>(&query);
         let debug_string = format!("{}", debug_query);
 
-        let begin = Self::bench_query_begin();
-        let res = self.connection.load(query);
-        Self::bench_query_end(begin, &debug_string);
-        res
+        async move {
+            self.instrumentation
+                .on_connection_event(InstrumentationEvent::start_query(&StrQueryHelper::new(
+                    &debug_string,
+                )));
+            let start_time = Instant::now();
+            let result = self.connection.load(query).await;
+            let duration = start_time.elapsed();
+            self.instrumentation
+                .on_connection_event(InstrumentationEvent::finish_query(
+                    &StrQueryHelper::new(&debug_string),
+                    result.as_ref().err(),
+                ));
+            if self.config.structured {
+                // The duration covers preparing and dispatching the query up
+                // to the point the row stream is ready; row counts aren't
+                // known until the caller consumes the stream, so the record
+                // omits `count`.
+                log_structured(&self.config, &debug_string, duration, None);
+            }
+            result
+        }
+        .boxed()
     }
 
     fn execute_returning_count<'conn, 'query, T>(
@@ -86,17 +313,37 @@ where
         let query_sql = format!("{}", debug_query);
 
         async move {
+            self.instrumentation
+                .on_connection_event(InstrumentationEvent::start_query(&StrQueryHelper::new(
+                    &query_sql,
+                )));
             let start_time = Instant::now();
             let result = self.connection.execute_returning_count(source).await;
             let duration = start_time.elapsed();
-            log_query(&query_sql, duration);
+            self.instrumentation
+                .on_connection_event(InstrumentationEvent::finish_query(
+                    &StrQueryHelper::new(&query_sql),
+                    result.as_ref().err(),
+                ));
+            if self.config.structured {
+                log_structured(&self.config, &query_sql, duration, result.as_ref().ok().copied());
+            }
             result
-        }.boxed()
+        }
+        .boxed()
     }
 
     fn transaction_state(&mut self) -> &mut LoggingTransactionManager<C> {
         &mut self.transaction_manager
     }
+
+    fn instrumentation(&mut self) -> &mut dyn Instrumentation {
+        &mut *self.instrumentation
+    }
+
+    fn set_instrumentation(&mut self, instrumentation: impl Instrumentation) {
+        self.instrumentation = Box::new(instrumentation);
+    }
 }
 
 impl<C> LoggingConnection<C>
@@ -127,13 +374,77 @@ where
     //     result
     // }
 
-    fn bench_query_begin() -> Instant {
-        Instant::now()
+}
+
+/// The default [`Instrumentation`] used by [`LoggingConnection`]: it pairs
+/// each `StartQuery` with its `FinishQuery` to recover the elapsed
+/// [`Duration`] and emits a `log` record through the connection's
+/// [`LoggingConfig`], preserving the original logging behaviour.
+pub struct LogInstrumentation {
+    config: LoggingConfig,
+    query_start: Option<Instant>,
+    connect_start: Option<Instant>,
+}
+
+impl LogInstrumentation {
+    /// Create a log-based instrumentation honouring `config`.
+    pub fn new(config: LoggingConfig) -> Self {
+        Self {
+            config,
+            query_start: None,
+            connect_start: None,
+        }
     }
+}
 
-    fn bench_query_end(start_time: Instant, query: &dyn Display) {
-        let duration = start_time.elapsed();
-        log_query(&query, duration);
+impl Instrumentation for LogInstrumentation {
+    fn on_connection_event(&mut self, event: InstrumentationEvent<'_>) {
+        match event {
+            InstrumentationEvent::StartEstablishConnection { .. } => {
+                self.connect_start = Some(Instant::now());
+            }
+            InstrumentationEvent::FinishEstablishConnection { url, error, .. } => {
+                let duration = self
+                    .connect_start
+                    .take()
+                    .map(|start| start.elapsed())
+                    .unwrap_or_default();
+                match error {
+                    Some(error) => warn!(
+                        "CONNECT FAILED [{:.1}ms]: {}: {}",
+                        duration_to_ms(duration),
+                        url,
+                        error
+                    ),
+                    None => debug!("CONNECT [{:.1}ms]: {}", duration_to_ms(duration), url),
+                }
+            }
+            InstrumentationEvent::StartQuery { .. } => {
+                self.query_start = Some(Instant::now());
+            }
+            // On the `load` path `FinishQuery` is emitted once the inner
+            // `load` has resolved, so the duration covers the query up to
+            // the point the row stream is ready; consuming the stream is
+            // not timed.
+            InstrumentationEvent::FinishQuery { query, error, .. } => {
+                let duration = self
+                    .query_start
+                    .take()
+                    .map(|start| start.elapsed())
+                    .unwrap_or_default();
+                if let Some(error) = error {
+                    warn!("QUERY FAILED: {}: {}", query, error);
+                }
+                // Structured output is emitted by the connection itself,
+                // where the bind parameters and row count are available.
+                if !self.config.structured {
+                    log_query(&self.config, &query, duration);
+                }
+            }
+            // Transactions are logged by `LoggingTransactionManager`; cache
+            // and all other events are ignored here.
+            _ => {}
+        }
     }
 }
 
@@ -142,9 +453,23 @@ where
     C: AsyncConnection,
 {
     pub fn new(connection: C) -> Self {
+        Self::with_config(connection, LoggingConfig::default())
+    }
+
+    /// Wrap `connection`, logging according to `config`.
+    ///
+    /// The connection starts out with the default [`LogInstrumentation`],
+    /// which reproduces the `log`-based behaviour; call
+    /// [`AsyncConnection::set_instrumentation`] to swap in tracing,
+    /// metrics, OpenTelemetry, or any other [`Instrumentation`].
+    pub fn with_config(connection: C, config: LoggingConfig) -> Self {
+        let instrumentation = Box::new(LogInstrumentation::new(config.clone()));
         Self {
             connection,
+            config,
+            instrumentation,
             transaction_manager: LoggingTransactionManager::<C> {
+                depth: 0,
                 phantom: PhantomData,
             },
         }
@@ -156,6 +481,9 @@ pub struct LoggingTransactionManager<C>
 where
     C: AsyncConnection,
 {
+    /// Current transaction nesting depth. `0` means no transaction is
+    /// open; `1` is a top-level `BEGIN`; higher values are SAVEPOINTs.
+    depth: usize,
     phantom: PhantomData<C>,
 }
 
@@ -169,24 +497,70 @@ where
     type TransactionStateData = Self;
 
     async fn begin_transaction(conn: &mut LoggingConnection<C>) -> QueryResult<()> {
-        <<C as AsyncConnection>::TransactionManager as TransactionManager<C>>::begin_transaction(
-            &mut conn.connection,
-        )
-        .await
+        let depth = conn.transaction_manager.depth;
+        let label = if depth == 0 {
+            "BEGIN".to_string()
+        } else {
+            format!("SAVEPOINT {}", depth)
+        };
+        let config = conn.config.clone();
+
+        let start = Instant::now();
+        let res =
+            <<C as AsyncConnection>::TransactionManager as TransactionManager<C>>::begin_transaction(
+                &mut conn.connection,
+            )
+            .await;
+        log_query(&config, &label, start.elapsed());
+        if res.is_ok() {
+            conn.transaction_manager.depth += 1;
+        }
+        res
     }
 
     async fn rollback_transaction(conn: &mut LoggingConnection<C>) -> QueryResult<()> {
-        <<C as AsyncConnection>::TransactionManager as TransactionManager<C>>::rollback_transaction(
+        warn_if_broken(conn, "ROLLBACK");
+        let depth = conn.transaction_manager.depth.saturating_sub(1);
+        let label = if depth == 0 {
+            "ROLLBACK".to_string()
+        } else {
+            format!("ROLLBACK TO SAVEPOINT {}", depth)
+        };
+        let config = conn.config.clone();
+
+        let start = Instant::now();
+        let res = <<C as AsyncConnection>::TransactionManager as TransactionManager<C>>::rollback_transaction(
             &mut conn.connection,
         )
-        .await
+        .await;
+        log_query(&config, &label, start.elapsed());
+        if res.is_ok() {
+            conn.transaction_manager.depth = depth;
+        }
+        res
     }
 
     async fn commit_transaction(conn: &mut LoggingConnection<C>) -> QueryResult<()> {
-        <<C as AsyncConnection>::TransactionManager as TransactionManager<C>>::commit_transaction(
-            &mut conn.connection,
-        )
-        .await
+        warn_if_broken(conn, "COMMIT");
+        let depth = conn.transaction_manager.depth.saturating_sub(1);
+        let label = if depth == 0 {
+            "COMMIT".to_string()
+        } else {
+            format!("RELEASE SAVEPOINT {}", depth)
+        };
+        let config = conn.config.clone();
+
+        let start = Instant::now();
+        let res =
+            <<C as AsyncConnection>::TransactionManager as TransactionManager<C>>::commit_transaction(
+                &mut conn.connection,
+            )
+            .await;
+        log_query(&config, &label, start.elapsed());
+        if res.is_ok() {
+            conn.transaction_manager.depth = depth;
+        }
+        res
     }
 
     fn transaction_manager_status_mut(
@@ -198,21 +572,224 @@ where
     }
 }
 
-fn log_query(query: &dyn Display, duration: Duration) {
-    if duration.as_secs() >= 5 {
-        warn!(
-            "SLOW QUERY [{:.2} s]: {}",
-            duration_to_secs(duration),
-            query
-        );
-    } else if duration.as_secs() >= 1 {
-        info!(
-            "SLOW QUERY [{:.2} s]: {}",
-            duration_to_secs(duration),
-            query
-        );
-    } else {
-        debug!("QUERY: [{:.1}ms]: {}", duration_to_ms(duration), query);
+/// Warn when a commit/rollback is requested while diesel has already
+/// marked the transaction manager as broken — a frequent source of
+/// transaction errors that otherwise get silently swallowed.
+fn warn_if_broken<C>(conn: &mut LoggingConnection<C>, op: &str)
+where
+    C: AsyncConnection + 'static,
+    <C as AsyncConnection>::Backend: std::default::Default,
+    <C::Backend as Backend>::QueryBuilder: Default,
+{
+    let status = <LoggingTransactionManager<C> as TransactionManager<LoggingConnection<C>>>::transaction_manager_status_mut(conn);
+    if matches!(status, TransactionManagerStatus::InError) {
+        warn!("{} requested while transaction manager is in the broken state", op);
+    }
+}
+
+/// Emit a structured JSON record for a query, applying the config's
+/// redaction policy to the captured bind parameters. `debugged` is the
+/// `Display` form of diesel's `debug_query`, which keeps the SQL template
+/// and its binds separate (`"<sql> -- binds: [..]"`).
+fn log_structured(
+    config: &LoggingConfig,
+    debugged: &str,
+    duration: Duration,
+    count: Option<usize>,
+) {
+    let level = match config.level_for(duration) {
+        Some(level) => level,
+        None => return,
+    };
+
+    let (sql, params) = split_debug_query(debugged);
+    let mut record = String::from("{\"sql\":");
+    push_json_string(&mut record, sql);
+    record.push_str(&format!(",\"duration_ms\":{:.1}", duration_to_ms(duration)));
+    if let Some(count) = count {
+        record.push_str(&format!(",\"count\":{}", count));
+    }
+    record.push_str(",\"params\":[");
+    for (position, param) in params.iter().enumerate() {
+        if position > 0 {
+            record.push(',');
+        }
+        if config.redaction.redacts(position) {
+            push_json_string(&mut record, Redaction::PLACEHOLDER);
+        } else {
+            push_json_string(&mut record, param);
+        }
+    }
+    record.push_str("]}");
+
+    match &config.target {
+        Some(target) => log!(target: target, level, "{}", record),
+        None => log!(level, "{}", record),
+    }
+}
+
+/// Split the `Display` form of `debug_query` into its SQL template and the
+/// list of rendered bind parameters.
+///
+/// The binds are recovered by splitting on `", "`, so a textual bind whose
+/// own value contains `", "` will be over-split into multiple params; the
+/// SQL template is always recovered correctly.
+fn split_debug_query(debugged: &str) -> (&str, Vec<&str>) {
+    match debugged.split_once(" -- binds: ") {
+        Some((sql, binds)) => {
+            let binds = binds.trim().trim_start_matches('[').trim_end_matches(']');
+            let params = if binds.is_empty() {
+                Vec::new()
+            } else {
+                binds.split(", ").collect()
+            };
+            (sql.trim(), params)
+        }
+        None => (debugged.trim(), Vec::new()),
+    }
+}
+
+/// Append `value` to `out` as a JSON string literal, escaping as needed.
+fn push_json_string(out: &mut String, value: &str) {
+    out.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn log_query(config: &LoggingConfig, query: &dyn Display, duration: Duration) {
+    let level = match config.level_for(duration) {
+        Some(level) => level,
+        None => return,
+    };
+
+    // `log!` needs a literal or pre-resolved target, so split on whether
+    // the config supplies one rather than duplicating the message.
+    match level {
+        level if duration >= config.info_threshold => {
+            let message = format_args!(
+                "SLOW QUERY [{:.2} s]: {}",
+                duration_to_secs(duration),
+                query
+            );
+            match &config.target {
+                Some(target) => log!(target: target, level, "{}", message),
+                None => log!(level, "{}", message),
+            }
+        }
+        level => {
+            let message = format_args!("QUERY: [{:.1}ms]: {}", duration_to_ms(duration), query);
+            match &config.target {
+                Some(target) => log!(target: target, level, "{}", message),
+                None => log!(level, "{}", message),
+            }
+        }
+    }
+}
+
+/// A synchronous [`diesel::Connection`] view over a
+/// [`LoggingConnection`], following `diesel_async`'s
+/// [`AsyncConnectionWrapper`]: it drives the async calls to completion on
+/// the current runtime so projects can run `diesel_migrations` without
+/// linking `libpq`/`libmysqlclient`.
+///
+/// The payoff specific to this crate is that migration statements issued
+/// through the wrapper's `batch_execute`/`load` still flow through the
+/// timing-and-logging layer, so schema setup becomes visible in the logs
+/// with the same slow-query thresholds as normal queries. The futures of
+/// `load` and `execute_returning_count` are `Send` because the query types
+/// are required to be `Send`; the query is moved into the future and its
+/// bind parameters are not serialized before the future is constructed.
+pub type LoggingConnectionWrapper<C> =
+    diesel_async::async_connection_wrapper::AsyncConnectionWrapper<LoggingConnection<C>>;
+
+impl<C> LoggingConnection<C>
+where
+    C: AsyncConnection + 'static,
+    <C as AsyncConnection>::Backend: std::default::Default,
+    <C::Backend as Backend>::QueryBuilder: Default,
+{
+    /// Turn this connection into a blocking [`LoggingConnectionWrapper`]
+    /// suitable for `diesel_migrations`.
+    pub fn into_sync_wrapper(self) -> LoggingConnectionWrapper<C> {
+        LoggingConnectionWrapper::from(self)
+    }
+}
+
+/// A [`diesel_async`] pool manager that hands out pooled
+/// `LoggingConnection<C>` values, so bb8/deadpool/mobc topologies get the
+/// timing-and-logging layer for free.
+///
+/// Because [`LoggingConnection`] is itself an [`AsyncConnection`], the
+/// existing [`AsyncDieselConnectionManager`] already knows how to manage
+/// it; this alias just names the common case. Use
+/// [`LoggingConnection::pooled_manager`] to build one whose connections
+/// carry a custom [`LoggingConfig`].
+pub type LoggingConnectionManager<C> = AsyncDieselConnectionManager<LoggingConnection<C>>;
+
+impl<C> LoggingConnection<C>
+where
+    C: AsyncConnection + 'static,
+    <C as AsyncConnection>::Backend: std::default::Default,
+    <C::Backend as Backend>::QueryBuilder: Default,
+{
+    /// Build a pool manager whose connections are established and then
+    /// wrapped with `config` before being handed to the pool, so recycle
+    /// checks and every pooled query flow through this layer.
+    pub fn pooled_manager(database_url: &str, config: LoggingConfig) -> LoggingConnectionManager<C> {
+        AsyncDieselConnectionManager::new_with_setup(database_url, move |url| {
+            let url = url.to_string();
+            let config = config.clone();
+            async move {
+                Ok(LoggingConnection::with_config(
+                    C::establish(&url).await?,
+                    config,
+                ))
+            }
+            .boxed()
+        })
+    }
+}
+
+impl<C> PoolableConnection for LoggingConnection<C>
+where
+    C: PoolableConnection + 'static,
+    <C as AsyncConnection>::Backend: std::default::Default,
+    <C::Backend as Backend>::QueryBuilder: Default,
+{
+    fn ping(&mut self, config: &RecyclingMethod<Self>) -> BoxFuture<'_, QueryResult<()>> {
+        let logging_config = self.config.clone();
+        async move {
+            match config {
+                RecyclingMethod::Fast => Ok(()),
+                RecyclingMethod::Verified => {
+                    // Route the probe through the logging layer so recycle
+                    // checks are themselves timed, analogous to sqlx's `ping`.
+                    let start = Instant::now();
+                    let res = self.connection.batch_execute("SELECT 1").await;
+                    log_query(&logging_config, &"PING SELECT 1", start.elapsed());
+                    res
+                }
+                RecyclingMethod::CustomFunction(check) => check(self).await,
+                // `RecyclingMethod` is `#[non_exhaustive]`; treat unknown
+                // variants as a no-op fast check.
+                _ => Ok(()),
+            }
+        }
+        .boxed()
+    }
+
+    fn is_broken(&mut self) -> bool {
+        self.connection.is_broken()
     }
 }
 